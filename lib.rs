@@ -5,6 +5,7 @@ mod eternalog {
     use ink::prelude::vec::Vec;
     use ink::prelude::string::String;
     use ink::storage::Mapping;
+    use ink::env::hash::Blake2x256;
 
     /// A log entry stored on chain
     #[derive(Debug, Clone, PartialEq, Eq)]
@@ -32,6 +33,7 @@ mod eternalog {
         #[ink(topic)]
         log_type: u32,
         data: String,
+        chain_head: [u8; 32],
     }
 
     #[ink(event)]
@@ -43,15 +45,92 @@ mod eternalog {
     }
 
     #[ink(event)]
-    pub struct StorageFeeUpdated {
+    pub struct FeeScheduleUpdated {
         #[ink(topic)]
-        old_fee: Balance,
+        old_base_fee: Balance,
         #[ink(topic)]
-        new_fee: Balance,
+        new_base_fee: Balance,
+        old_fee_per_byte: Balance,
+        new_fee_per_byte: Balance,
         #[ink(topic)]
         updated_by: AccountId,
     }
 
+    #[ink(event)]
+    pub struct ContractPaused {
+        #[ink(topic)]
+        paused_by: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct ContractResumed {
+        #[ink(topic)]
+        resumed_by: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct FeeCollected {
+        #[ink(topic)]
+        amount: Balance,
+        #[ink(topic)]
+        payer: AccountId,
+        #[ink(topic)]
+        treasury: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct FeeSinkUpdated {
+        old_fee_sink: FeeSink,
+        new_fee_sink: FeeSink,
+        #[ink(topic)]
+        updated_by: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct WriterGranted {
+        #[ink(topic)]
+        log_type: u32,
+        #[ink(topic)]
+        account: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct WriterRevoked {
+        #[ink(topic)]
+        log_type: u32,
+        #[ink(topic)]
+        account: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct UpgradeProposed {
+        #[ink(topic)]
+        code_hash: Hash,
+        available_at: BlockNumber,
+    }
+
+    #[ink(event)]
+    pub struct UpgradeExecuted {
+        #[ink(topic)]
+        code_hash: Hash,
+    }
+
+    #[ink(event)]
+    pub struct UpgradeCanceled {
+        #[ink(topic)]
+        code_hash: Hash,
+    }
+
+    /// Where fees charged by `store_log` end up
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    pub enum FeeSink {
+        /// Fees stay locked in the contract balance, effectively burned
+        Burn,
+        /// Fees are transferred to the given treasury account
+        Treasury(AccountId),
+    }
+
     /// Custom errors
     #[derive(Debug, PartialEq, Eq)]
     #[ink::scale_derive(Encode, Decode, TypeInfo)]
@@ -66,6 +145,18 @@ mod eternalog {
         EmptyLogData,
         /// Only the contract owner can perform this action
         Unauthorized,
+        /// The contract is paused and not accepting new logs
+        ContractPaused,
+        /// Transferring the collected fee to the treasury account failed
+        TransferFailed,
+        /// Caller is not on the allowlist configured for this log type
+        UnauthorizedWriter,
+        /// No upgrade has been proposed
+        NoPendingUpgrade,
+        /// The timelock delay for the pending upgrade has not elapsed yet
+        UpgradeTimelocked,
+        /// Setting the new code hash failed
+        UpgradeFailed,
     }
 
     /// Defines the storage of the contract
@@ -75,49 +166,101 @@ mod eternalog {
         owner: AccountId,
         /// Counter for generating unique log IDs
         next_log_id: u64,
-        /// Storage fee per log entry (in native token units)
-        storage_fee: Balance,
+        /// Flat fee charged per log entry, regardless of size (in native token units)
+        base_fee: Balance,
+        /// Additional fee charged per byte of log data
+        fee_per_byte: Balance,
         /// Total number of logs stored
         total_logs: u64,
         /// Total fees burned
         total_fees_burned: Balance,
+        /// Total fees transferred to the treasury
+        total_fees_collected: Balance,
+        /// Where fees charged by `store_log` are routed
+        fee_sink: FeeSink,
         /// Individual log data stored as separate mappings to avoid storage layout issues
         log_data: Mapping<u64, String>,
         log_types: Mapping<u64, u32>,
         log_authors: Mapping<u64, AccountId>,
         log_timestamps: Mapping<u64, BlockNumber>,
-        /// Indices for searching
-        logs_by_type: Mapping<u32, Vec<u64>>,
-        logs_by_author: Mapping<AccountId, Vec<u64>>,
+        /// Indices for searching, keyed by `(index_key, position)` -> `log_id`
+        /// so a bounded page reads only the positions it needs instead of
+        /// decoding every log ID ever recorded for a type/author/pair.
+        type_log_counts: Mapping<u32, u64>,
+        type_log_index: Mapping<(u32, u64), u64>,
+        author_log_counts: Mapping<AccountId, u64>,
+        author_log_index: Mapping<(AccountId, u64), u64>,
+        type_author_log_counts: Mapping<(u32, AccountId), u64>,
+        type_author_log_index: Mapping<(u32, AccountId, u64), u64>,
+        /// Head of the tamper-evident hash chain over all stored logs
+        chain_head: [u8; 32],
+        /// Per-log hash chain link, keyed by log id
+        log_chain: Mapping<u64, [u8; 32]>,
+        /// Whether the contract is currently paused for new writes
+        is_paused: bool,
+        /// Per-type allowlist of accounts permitted to write; a type with an
+        /// empty/unconfigured set remains open to everyone
+        type_writers: Mapping<u32, Vec<AccountId>>,
+        /// Code hash proposed by `propose_upgrade`, pending its timelock
+        pending_code_hash: Option<Hash>,
+        /// Block at which the pending upgrade was proposed
+        pending_code_block: BlockNumber,
+        /// Minimum number of blocks that must elapse between proposing and
+        /// executing a code upgrade
+        upgrade_delay_blocks: BlockNumber,
     }
 
     impl Eternalog {
-        /// Constructor that initializes the contract with a storage fee
+        /// Constructor that initializes the contract with a base fee, a
+        /// per-byte fee charged on top of it, where charged fees go, and
+        /// the timelock delay required between proposing and executing a
+        /// code upgrade
         #[ink(constructor)]
-        pub fn new(storage_fee: Balance) -> Self {
+        pub fn new(
+            base_fee: Balance,
+            fee_per_byte: Balance,
+            fee_sink: FeeSink,
+            upgrade_delay_blocks: BlockNumber,
+        ) -> Self {
             Self {
                 owner: Self::env().caller(),
                 next_log_id: 1,
-                storage_fee,
+                base_fee,
+                fee_per_byte,
                 total_logs: 0,
                 total_fees_burned: 0,
+                total_fees_collected: 0,
+                fee_sink,
                 log_data: Mapping::default(),
                 log_types: Mapping::default(),
                 log_authors: Mapping::default(),
                 log_timestamps: Mapping::default(),
-                logs_by_type: Mapping::default(),
-                logs_by_author: Mapping::default(),
+                type_log_counts: Mapping::default(),
+                type_log_index: Mapping::default(),
+                author_log_counts: Mapping::default(),
+                author_log_index: Mapping::default(),
+                type_author_log_counts: Mapping::default(),
+                type_author_log_index: Mapping::default(),
+                chain_head: [0u8; 32],
+                log_chain: Mapping::default(),
+                is_paused: false,
+                type_writers: Mapping::default(),
+                pending_code_hash: None,
+                pending_code_block: 0,
+                upgrade_delay_blocks,
             }
         }
 
-        /// Constructor with default storage fee (10 units)
+        /// Constructor with default fee schedule (10 unit base fee, no
+        /// per-byte fee), fees burned, and a 100-block upgrade timelock
         #[ink(constructor)]
         pub fn default() -> Self {
-            Self::new(10)
+            Self::new(10, 0, FeeSink::Burn, 100)
         }
 
         /// Store a new log entry on chain
-        /// Charges a fee that gets burned
+        /// Charges a fee that is burned or sent to the treasury, depending
+        /// on the configured fee sink
         #[ink(message, payable)]
         pub fn store_log(&mut self, data: String, log_type: u32) -> Result<u64, Error> {
             // Validate inputs
@@ -127,36 +270,82 @@ mod eternalog {
             if log_type == 0 {
                 return Err(Error::InvalidLogType);
             }
+            if self.is_paused {
+                return Err(Error::ContractPaused);
+            }
 
-            // Check payment and burn fee
+            let caller = self.env().caller();
+            let writers = self.type_writers.get(log_type).unwrap_or_default();
+            if !writers.is_empty() && !writers.contains(&caller) {
+                return Err(Error::UnauthorizedWriter);
+            }
+
+            // Check payment and route the fee to its configured sink
             let payment = self.env().transferred_value();
-            if payment < self.storage_fee {
+            let required_fee = self.quote_fee(data.len() as u32);
+            if payment < required_fee {
                 return Err(Error::InsufficientBalance);
             }
+            if let FeeSink::Treasury(treasury) = self.fee_sink {
+                // Only the quoted fee is forwarded; any overpayment stays in
+                // the contract's own balance, same as the burn path.
+                if self.env().transfer(treasury, required_fee).is_err() {
+                    return Err(Error::TransferFailed);
+                }
+            }
 
-            let caller = self.env().caller();
             let current_block = self.env().block_number();
             let log_id = self.next_log_id;
 
+            // Extend the tamper-evident hash chain
+            let prev_head = self.chain_head;
+            let mut new_head = [0u8; 32];
+            ink::env::hash_encoded::<Blake2x256, _>(
+                &(prev_head, log_id, caller, log_type, current_block, &data),
+                &mut new_head,
+            );
+            self.log_chain.insert(log_id, &new_head);
+            self.chain_head = new_head;
+
             // Store log components separately
             self.log_data.insert(log_id, &data);
             self.log_types.insert(log_id, &log_type);
             self.log_authors.insert(log_id, &caller);
             self.log_timestamps.insert(log_id, &current_block);
 
-            // Update indices
-            let mut type_logs = self.logs_by_type.get(log_type).unwrap_or_default();
-            type_logs.push(log_id);
-            self.logs_by_type.insert(log_type, &type_logs);
+            // Update indices. Each is keyed by `(key, position)` -> `log_id`
+            // with a parallel count, so a later page read touches only the
+            // positions it needs rather than the whole type/author history.
+            let type_count = self.type_log_counts.get(log_type).unwrap_or(0);
+            self.type_log_index.insert((log_type, type_count), &log_id);
+            self.type_log_counts.insert(log_type, &type_count.saturating_add(1));
+
+            let author_count = self.author_log_counts.get(caller).unwrap_or(0);
+            self.author_log_index.insert((caller, author_count), &log_id);
+            self.author_log_counts
+                .insert(caller, &author_count.saturating_add(1));
 
-            let mut author_logs = self.logs_by_author.get(caller).unwrap_or_default();
-            author_logs.push(log_id);
-            self.logs_by_author.insert(caller, &author_logs);
+            let type_author_count = self
+                .type_author_log_counts
+                .get((log_type, caller))
+                .unwrap_or(0);
+            self.type_author_log_index
+                .insert((log_type, caller, type_author_count), &log_id);
+            self.type_author_log_counts
+                .insert((log_type, caller), &type_author_count.saturating_add(1));
 
             // Update counters
             self.next_log_id = self.next_log_id.saturating_add(1);
             self.total_logs = self.total_logs.saturating_add(1);
-            self.total_fees_burned = self.total_fees_burned.saturating_add(payment);
+            match self.fee_sink {
+                FeeSink::Burn => {
+                    self.total_fees_burned = self.total_fees_burned.saturating_add(payment);
+                }
+                FeeSink::Treasury(_) => {
+                    self.total_fees_collected =
+                        self.total_fees_collected.saturating_add(required_fee);
+                }
+            }
 
             // Emit events
             self.env().emit_event(LogStored {
@@ -164,12 +353,24 @@ mod eternalog {
                 author: caller,
                 log_type,
                 data,
+                chain_head: new_head,
             });
 
-            self.env().emit_event(FeeBurned {
-                amount: payment,
-                burner: caller,
-            });
+            match self.fee_sink {
+                FeeSink::Burn => {
+                    self.env().emit_event(FeeBurned {
+                        amount: payment,
+                        burner: caller,
+                    });
+                }
+                FeeSink::Treasury(treasury) => {
+                    self.env().emit_event(FeeCollected {
+                        amount: required_fee,
+                        payer: caller,
+                        treasury,
+                    });
+                }
+            }
 
             Ok(log_id)
         }
@@ -196,56 +397,200 @@ mod eternalog {
             }
         }
 
-        /// Get all log IDs for a specific type
+        /// Get all log IDs for a specific type. Unbounded: reads one
+        /// storage entry per matching log, so prefer
+        /// `get_logs_by_type_paged` once a type accumulates many entries.
         #[ink(message)]
         pub fn get_logs_by_type(&self, log_type: u32) -> Vec<u64> {
-            self.logs_by_type.get(log_type).unwrap_or_default()
+            let count = self.type_log_counts.get(log_type).unwrap_or(0);
+            (0..count)
+                .filter_map(|i| self.type_log_index.get((log_type, i)))
+                .collect()
         }
 
-        /// Get all log IDs for a specific author
+        /// Get all log IDs for a specific author. Unbounded: reads one
+        /// storage entry per matching log, so prefer
+        /// `get_logs_by_author_paged` once an author accumulates many entries.
         #[ink(message)]
         pub fn get_logs_by_author(&self, author: AccountId) -> Vec<u64> {
-            self.logs_by_author.get(author).unwrap_or_default()
+            let count = self.author_log_counts.get(author).unwrap_or(0);
+            (0..count)
+                .filter_map(|i| self.author_log_index.get((author, i)))
+                .collect()
         }
 
-        /// Search logs by content (simple substring search)
-        /// Returns vector of log IDs that contain the search term
+        /// Search logs by content (simple substring search) over the given
+        /// inclusive ID range. Returns vector of log IDs that contain the
+        /// search term. Unlike the type/author indices, log content isn't
+        /// indexed for search, so this always reads every log in the range
+        /// — callers should keep `from_id..=to_id` narrow (e.g. one page's
+        /// worth of IDs at a time) rather than always scanning the full
+        /// `1..next_log_id` history.
         #[ink(message)]
-        pub fn search_logs_by_content(&self, search_term: String) -> Vec<u64> {
+        pub fn search_logs_by_content(
+            &self,
+            search_term: String,
+            from_id: u64,
+            to_id: u64,
+        ) -> Vec<u64> {
             let mut results = Vec::new();
-            
-            for log_id in 1..self.next_log_id {
+
+            let to_id = to_id.min(self.next_log_id.saturating_sub(1));
+            if from_id == 0 || from_id > to_id {
+                return results;
+            }
+
+            for log_id in from_id..=to_id {
                 if let Some(data) = self.log_data.get(log_id) {
                     if data.contains(&search_term) {
                         results.push(log_id);
                     }
                 }
             }
-            
+
             results
         }
 
-        /// Get logs by both type and author
+        /// Get logs by both type and author. Unbounded: reads one storage
+        /// entry per matching log, so prefer
+        /// `get_logs_by_type_and_author_paged` once a type/author pair
+        /// accumulates many entries.
         #[ink(message)]
         pub fn get_logs_by_type_and_author(&self, log_type: u32, author: AccountId) -> Vec<u64> {
-            let type_logs = self.get_logs_by_type(log_type);
-            let author_logs = self.get_logs_by_author(author);
-            
-            // Find intersection
-            let mut results = Vec::new();
-            for log_id in type_logs {
-                if author_logs.contains(&log_id) {
-                    results.push(log_id);
-                }
-            }
-            
-            results
+            let count = self
+                .type_author_log_counts
+                .get((log_type, author))
+                .unwrap_or(0);
+            (0..count)
+                .filter_map(|i| self.type_author_log_index.get((log_type, author, i)))
+                .collect()
+        }
+
+        /// Get a bounded page of log IDs for a specific type. Reads only
+        /// the `limit` entries the page needs from the type index, rather
+        /// than the type's whole log history.
+        /// Returns the page plus the total number of matching entries so
+        /// callers can walk large result sets in fixed-size pages.
+        #[ink(message)]
+        pub fn get_logs_by_type_paged(
+            &self,
+            log_type: u32,
+            start: u64,
+            limit: u32,
+        ) -> (Vec<u64>, u64) {
+            let total = self.type_log_counts.get(log_type).unwrap_or(0);
+            Self::paginate_indexed(total, start, limit, |i| {
+                self.type_log_index.get((log_type, i))
+            })
         }
 
-        /// Get the current storage fee
+        /// Get a bounded page of log IDs for a specific author. Reads only
+        /// the `limit` entries the page needs from the author index,
+        /// rather than the author's whole log history.
+        /// Returns the page plus the total number of matching entries.
         #[ink(message)]
-        pub fn get_storage_fee(&self) -> Balance {
-            self.storage_fee
+        pub fn get_logs_by_author_paged(
+            &self,
+            author: AccountId,
+            start: u64,
+            limit: u32,
+        ) -> (Vec<u64>, u64) {
+            let total = self.author_log_counts.get(author).unwrap_or(0);
+            Self::paginate_indexed(total, start, limit, |i| {
+                self.author_log_index.get((author, i))
+            })
+        }
+
+        /// Get a bounded page of log IDs matching a content search within
+        /// `from_id..=to_id`. The caller is responsible for keeping that
+        /// range narrow (e.g. one page's worth of IDs) so a single call
+        /// can't be forced to rescan the whole log history; unlike the
+        /// type/author pages, this still reads every log in the given
+        /// range before slicing, since content isn't indexed.
+        /// Returns the page plus the total number of matching entries
+        /// found within the given range.
+        #[ink(message)]
+        pub fn search_logs_by_content_paged(
+            &self,
+            search_term: String,
+            from_id: u64,
+            to_id: u64,
+            start: u64,
+            limit: u32,
+        ) -> (Vec<u64>, u64) {
+            Self::paginate_slice(
+                &self.search_logs_by_content(search_term, from_id, to_id),
+                start,
+                limit,
+            )
+        }
+
+        /// Get a bounded page of log IDs matching both type and author.
+        /// Reads only the `limit` entries the page needs from the
+        /// combined type+author index, rather than the full intersection.
+        /// Returns the page plus the total number of matching entries.
+        #[ink(message)]
+        pub fn get_logs_by_type_and_author_paged(
+            &self,
+            log_type: u32,
+            author: AccountId,
+            start: u64,
+            limit: u32,
+        ) -> (Vec<u64>, u64) {
+            let total = self
+                .type_author_log_counts
+                .get((log_type, author))
+                .unwrap_or(0);
+            Self::paginate_indexed(total, start, limit, |i| {
+                self.type_author_log_index.get((log_type, author, i))
+            })
+        }
+
+        /// Read a bounded page of `[start, start + limit)` directly out of
+        /// an indexed storage mapping via `read`, touching at most `limit`
+        /// storage entries regardless of `total`.
+        fn paginate_indexed(
+            total: u64,
+            start: u64,
+            limit: u32,
+            read: impl Fn(u64) -> Option<u64>,
+        ) -> (Vec<u64>, u64) {
+            let start = start.min(total);
+            let end = start.saturating_add(limit as u64).min(total);
+            let page = (start..end).filter_map(read).collect();
+            (page, total)
+        }
+
+        /// Slice an already-materialized `items` buffer into a fixed-size
+        /// page starting at `start`, returning the page alongside the
+        /// total item count. Used where the full set must already be read
+        /// into memory (content search), unlike `paginate_indexed` which
+        /// reads only the page's worth of storage entries.
+        fn paginate_slice(items: &[u64], start: u64, limit: u32) -> (Vec<u64>, u64) {
+            let total = items.len() as u64;
+            let start = start.min(total) as usize;
+            let end = start.saturating_add(limit as usize).min(items.len());
+            (items[start..end].to_vec(), total)
+        }
+
+        /// Get the current base fee
+        #[ink(message)]
+        pub fn get_base_fee(&self) -> Balance {
+            self.base_fee
+        }
+
+        /// Get the current per-byte fee
+        #[ink(message)]
+        pub fn get_fee_per_byte(&self) -> Balance {
+            self.fee_per_byte
+        }
+
+        /// Quote the fee that would be charged for storing `data_len` bytes
+        /// of log data: `base_fee + fee_per_byte * data_len`
+        #[ink(message)]
+        pub fn quote_fee(&self, data_len: u32) -> Balance {
+            self.base_fee
+                .saturating_add(self.fee_per_byte.saturating_mul(data_len as Balance))
         }
 
         /// Get total number of logs stored
@@ -260,30 +605,71 @@ mod eternalog {
             self.total_fees_burned
         }
 
+        /// Get total fees transferred to the treasury
+        #[ink(message)]
+        pub fn get_total_fees_collected(&self) -> Balance {
+            self.total_fees_collected
+        }
+
+        /// Get where charged fees are currently routed
+        #[ink(message)]
+        pub fn get_fee_sink(&self) -> FeeSink {
+            self.fee_sink
+        }
+
         /// Get the next log ID that will be assigned
         #[ink(message)]
         pub fn get_next_log_id(&self) -> u64 {
             self.next_log_id
         }
 
-        /// Update storage fee (only contract owner can call this)
+        /// Update the fee schedule (only contract owner can call this)
         #[ink(message)]
-        pub fn update_storage_fee(&mut self, new_fee: Balance) -> Result<(), Error> {
+        pub fn update_fee_schedule(
+            &mut self,
+            base_fee: Balance,
+            fee_per_byte: Balance,
+        ) -> Result<(), Error> {
             let caller = self.env().caller();
             if caller != self.owner {
                 return Err(Error::Unauthorized);
             }
-            
-            let old_fee = self.storage_fee;
-            self.storage_fee = new_fee;
-            
+
+            let old_base_fee = self.base_fee;
+            let old_fee_per_byte = self.fee_per_byte;
+            self.base_fee = base_fee;
+            self.fee_per_byte = fee_per_byte;
+
             // Emit event
-            self.env().emit_event(StorageFeeUpdated {
-                old_fee,
-                new_fee,
+            self.env().emit_event(FeeScheduleUpdated {
+                old_base_fee,
+                new_base_fee: base_fee,
+                old_fee_per_byte,
+                new_fee_per_byte: fee_per_byte,
                 updated_by: caller,
             });
-            
+
+            Ok(())
+        }
+
+        /// Update where charged fees are routed (only contract owner can call this)
+        #[ink(message)]
+        pub fn update_fee_sink(&mut self, fee_sink: FeeSink) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.owner {
+                return Err(Error::Unauthorized);
+            }
+
+            let old_fee_sink = self.fee_sink;
+            self.fee_sink = fee_sink;
+
+            // Emit event
+            self.env().emit_event(FeeSinkUpdated {
+                old_fee_sink,
+                new_fee_sink: fee_sink,
+                updated_by: caller,
+            });
+
             Ok(())
         }
 
@@ -292,6 +678,220 @@ mod eternalog {
         pub fn get_owner(&self) -> AccountId {
             self.owner
         }
+
+        /// Pause the contract, rejecting new `store_log` calls until resumed
+        /// (only the contract owner can call this)
+        #[ink(message)]
+        pub fn pause(&mut self) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.owner {
+                return Err(Error::Unauthorized);
+            }
+
+            self.is_paused = true;
+            self.env().emit_event(ContractPaused { paused_by: caller });
+
+            Ok(())
+        }
+
+        /// Resume the contract, allowing `store_log` calls again
+        /// (only the contract owner can call this)
+        #[ink(message)]
+        pub fn resume(&mut self) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.owner {
+                return Err(Error::Unauthorized);
+            }
+
+            self.is_paused = false;
+            self.env().emit_event(ContractResumed { resumed_by: caller });
+
+            Ok(())
+        }
+
+        /// Check whether the contract is currently paused
+        #[ink(message)]
+        pub fn is_paused(&self) -> bool {
+            self.is_paused
+        }
+
+        /// Grant an account permission to write a log type (only contract
+        /// owner can call this). Once a log type has a non-empty writer
+        /// set, only allowlisted accounts may store logs of that type.
+        #[ink(message)]
+        pub fn grant_writer(&mut self, log_type: u32, account: AccountId) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.owner {
+                return Err(Error::Unauthorized);
+            }
+
+            let mut writers = self.type_writers.get(log_type).unwrap_or_default();
+            if !writers.contains(&account) {
+                writers.push(account);
+                self.type_writers.insert(log_type, &writers);
+            }
+
+            self.env().emit_event(WriterGranted { log_type, account });
+
+            Ok(())
+        }
+
+        /// Revoke an account's permission to write a log type (only
+        /// contract owner can call this)
+        #[ink(message)]
+        pub fn revoke_writer(&mut self, log_type: u32, account: AccountId) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.owner {
+                return Err(Error::Unauthorized);
+            }
+
+            let mut writers = self.type_writers.get(log_type).unwrap_or_default();
+            writers.retain(|writer| writer != &account);
+            self.type_writers.insert(log_type, &writers);
+
+            self.env().emit_event(WriterRevoked { log_type, account });
+
+            Ok(())
+        }
+
+        /// Get the current writer allowlist for a log type (empty means
+        /// the type is open to everyone)
+        #[ink(message)]
+        pub fn get_type_writers(&self, log_type: u32) -> Vec<AccountId> {
+            self.type_writers.get(log_type).unwrap_or_default()
+        }
+
+        /// Propose a code hash to upgrade to, starting the timelock (only
+        /// contract owner can call this)
+        #[ink(message)]
+        pub fn propose_upgrade(&mut self, code_hash: Hash) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.owner {
+                return Err(Error::Unauthorized);
+            }
+
+            let current_block = self.env().block_number();
+            self.pending_code_hash = Some(code_hash);
+            self.pending_code_block = current_block;
+
+            self.env().emit_event(UpgradeProposed {
+                code_hash,
+                available_at: current_block.saturating_add(self.upgrade_delay_blocks),
+            });
+
+            Ok(())
+        }
+
+        /// Execute a previously proposed upgrade once its timelock has
+        /// elapsed (only contract owner can call this)
+        #[ink(message)]
+        pub fn execute_upgrade(&mut self) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.owner {
+                return Err(Error::Unauthorized);
+            }
+
+            let code_hash = self.pending_code_hash.ok_or(Error::NoPendingUpgrade)?;
+            if self.env().block_number()
+                < self.pending_code_block.saturating_add(self.upgrade_delay_blocks)
+            {
+                return Err(Error::UpgradeTimelocked);
+            }
+
+            self.env()
+                .set_code_hash(&code_hash)
+                .map_err(|_| Error::UpgradeFailed)?;
+            self.pending_code_hash = None;
+
+            self.env().emit_event(UpgradeExecuted { code_hash });
+
+            Ok(())
+        }
+
+        /// Cancel a pending upgrade before it takes effect (only contract
+        /// owner can call this)
+        #[ink(message)]
+        pub fn cancel_upgrade(&mut self) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.owner {
+                return Err(Error::Unauthorized);
+            }
+
+            if let Some(code_hash) = self.pending_code_hash.take() {
+                self.env().emit_event(UpgradeCanceled { code_hash });
+            }
+
+            Ok(())
+        }
+
+        /// Get the pending upgrade, if any, as `(code_hash, available_at)`
+        #[ink(message)]
+        pub fn get_pending_upgrade(&self) -> Option<(Hash, BlockNumber)> {
+            self.pending_code_hash
+                .map(|code_hash| (code_hash, self.pending_code_block.saturating_add(self.upgrade_delay_blocks)))
+        }
+
+        /// Get the current head of the tamper-evident hash chain
+        #[ink(message)]
+        pub fn get_chain_head(&self) -> [u8; 32] {
+            self.chain_head
+        }
+
+        /// Get the chain link hash stored for a specific log entry
+        #[ink(message)]
+        pub fn get_log_hash(&self, log_id: u64) -> Option<[u8; 32]> {
+            self.log_chain.get(log_id)
+        }
+
+        /// Verify that the hash chain over `[from_id, to_id]` is intact by
+        /// recomputing each link from the stored log components and
+        /// comparing it against the hash recorded at storage time.
+        ///
+        /// The previous head at `from_id - 1` (or the all-zero genesis head
+        /// when `from_id == 1`) is trusted as the anchor, so a client can
+        /// verify any suffix of the chain without replaying from entry 1.
+        #[ink(message)]
+        pub fn verify_chain(&self, from_id: u64, to_id: u64) -> bool {
+            if from_id == 0 || from_id > to_id || to_id >= self.next_log_id {
+                return false;
+            }
+
+            let mut prev_head = if from_id == 1 {
+                [0u8; 32]
+            } else {
+                match self.log_chain.get(from_id - 1) {
+                    Some(head) => head,
+                    None => return false,
+                }
+            };
+
+            for log_id in from_id..=to_id {
+                let (data, log_type, author, timestamp) = match (
+                    self.log_data.get(log_id),
+                    self.log_types.get(log_id),
+                    self.log_authors.get(log_id),
+                    self.log_timestamps.get(log_id),
+                ) {
+                    (Some(data), Some(log_type), Some(author), Some(timestamp)) => {
+                        (data, log_type, author, timestamp)
+                    }
+                    _ => return false,
+                };
+
+                let mut computed = [0u8; 32];
+                ink::env::hash_encoded::<Blake2x256, _>(
+                    &(prev_head, log_id, author, log_type, timestamp, &data),
+                    &mut computed,
+                );
+
+                match self.log_chain.get(log_id) {
+                    Some(stored) if stored == computed => prev_head = computed,
+                    _ => return false,
+                }
+            }
+
+            true
+        }
     }
 
     /// Unit tests
@@ -302,31 +902,33 @@ mod eternalog {
         #[ink::test]
         fn default_works() {
             let eternalog = Eternalog::default();
-            assert_eq!(eternalog.get_storage_fee(), 10);
+            assert_eq!(eternalog.get_base_fee(), 10);
+            assert_eq!(eternalog.get_fee_per_byte(), 0);
             assert_eq!(eternalog.get_total_logs(), 0);
         }
 
         #[ink::test]
         fn new_works() {
-            let eternalog = Eternalog::new(100);
-            assert_eq!(eternalog.get_storage_fee(), 100);
+            let eternalog = Eternalog::new(100, 1, FeeSink::Burn, 100);
+            assert_eq!(eternalog.get_base_fee(), 100);
+            assert_eq!(eternalog.get_fee_per_byte(), 1);
             assert_eq!(eternalog.get_total_logs(), 0);
         }
 
         #[ink::test]
         fn store_log_works() {
-            let mut eternalog = Eternalog::new(10);
-            
+            let mut eternalog = Eternalog::new(10, 0, FeeSink::Burn, 100);
+
             // Test storing a log (this won't work in unit tests due to payable, but tests the logic)
             let result = eternalog.store_log("Test log entry".to_string(), 1);
-            
+
             // In unit tests, transferred_value() returns 0, so this will fail
             assert_eq!(result, Err(Error::InsufficientBalance));
         }
 
         #[ink::test]
         fn validate_inputs() {
-            let mut eternalog = Eternalog::new(10);
+            let mut eternalog = Eternalog::new(10, 0, FeeSink::Burn, 100);
             
             // Test empty data
             let result = eternalog.store_log("".to_string(), 1);
@@ -346,16 +948,319 @@ mod eternalog {
 
         #[ink::test]
         fn only_owner_can_update_fee() {
-            let mut eternalog = Eternalog::new(100);
-            
+            let mut eternalog = Eternalog::new(100, 0, FeeSink::Burn, 100);
+
             // Owner should be able to update fee
-            let result = eternalog.update_storage_fee(200);
+            let result = eternalog.update_fee_schedule(200, 2);
             assert_eq!(result, Ok(()));
-            assert_eq!(eternalog.get_storage_fee(), 200);
-            
+            assert_eq!(eternalog.get_base_fee(), 200);
+            assert_eq!(eternalog.get_fee_per_byte(), 2);
+
             // Note: In unit tests, we can't easily test with different accounts
             // This would be better tested in E2E tests with different signers
         }
+
+        #[ink::test]
+        fn quote_fee_scales_with_data_length() {
+            let eternalog = Eternalog::new(10, 2, FeeSink::Burn, 100);
+            assert_eq!(eternalog.quote_fee(0), 10);
+            assert_eq!(eternalog.quote_fee(5), 20);
+        }
+
+        #[ink::test]
+        fn store_log_enforces_length_scaled_fee() {
+            let mut eternalog = Eternalog::new(10, 2, FeeSink::Burn, 100);
+
+            // "test" is 4 bytes, so the required fee is 10 + 2*4 = 18
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(17);
+            assert_eq!(
+                eternalog.store_log("test".to_string(), 1),
+                Err(Error::InsufficientBalance)
+            );
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(18);
+            assert!(eternalog.store_log("test".to_string(), 1).is_ok());
+        }
+
+        #[ink::test]
+        fn update_fee_schedule_changes_the_quoted_fee() {
+            let mut eternalog = Eternalog::new(10, 0, FeeSink::Burn, 100);
+            assert_eq!(eternalog.quote_fee(5), 10);
+
+            assert_eq!(eternalog.update_fee_schedule(100, 2), Ok(()));
+            assert_eq!(eternalog.quote_fee(5), 110);
+        }
+
+        #[ink::test]
+        fn treasury_sink_forwards_only_the_quoted_fee() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let contract = ink::env::test::callee::<ink::env::DefaultEnvironment>();
+            // The contract's own balance already holds the transferred payment
+            // by the time the message body runs; simulate that here.
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(contract, 25);
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(accounts.django, 0);
+
+            let mut eternalog = Eternalog::new(10, 0, FeeSink::Treasury(accounts.django), 100);
+
+            // Overpay the quoted fee of 10.
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(25);
+            assert!(eternalog.store_log("test".to_string(), 1).is_ok());
+
+            // Only the quoted fee is forwarded to the treasury...
+            assert_eq!(
+                ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(
+                    accounts.django
+                ),
+                Ok(10)
+            );
+            // ...the overpayment stays in the contract's own balance...
+            assert_eq!(
+                ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(contract),
+                Ok(15)
+            );
+            // ...and only the quoted fee is counted as collected, not the overpayment.
+            assert_eq!(eternalog.get_total_fees_collected(), 10);
+        }
+
+        #[ink::test]
+        fn type_writer_allowlist_restricts_store_log() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut eternalog = Eternalog::new(10, 0, FeeSink::Burn, 100);
+
+            // No allowlist yet, so anyone can write type 1.
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(10);
+            assert!(eternalog.store_log("open".to_string(), 1).is_ok());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(eternalog.grant_writer(1, accounts.alice), Ok(()));
+            assert_eq!(eternalog.get_type_writers(1), [accounts.alice]);
+
+            // Now type 1 is restricted to alice; bob is rejected.
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(10);
+            assert_eq!(
+                eternalog.store_log("restricted".to_string(), 1),
+                Err(Error::UnauthorizedWriter)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(10);
+            assert!(eternalog.store_log("restricted".to_string(), 1).is_ok());
+
+            assert_eq!(eternalog.revoke_writer(1, accounts.alice), Ok(()));
+            assert!(eternalog.get_type_writers(1).is_empty());
+        }
+
+        #[ink::test]
+        fn only_owner_can_grant_writer() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut eternalog = Eternalog::new(10, 0, FeeSink::Burn, 100);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                eternalog.grant_writer(1, accounts.bob),
+                Err(Error::Unauthorized)
+            );
+            assert_eq!(
+                eternalog.revoke_writer(1, accounts.bob),
+                Err(Error::Unauthorized)
+            );
+        }
+
+        #[ink::test]
+        fn propose_upgrade_requires_owner() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut eternalog = Eternalog::new(10, 0, FeeSink::Burn, 100);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                eternalog.propose_upgrade(Hash::from([1u8; 32])),
+                Err(Error::Unauthorized)
+            );
+            assert_eq!(eternalog.get_pending_upgrade(), None);
+        }
+
+        #[ink::test]
+        fn execute_upgrade_respects_timelock_and_requires_a_pending_upgrade() {
+            let mut eternalog = Eternalog::new(10, 0, FeeSink::Burn, 100);
+
+            // No pending upgrade yet.
+            assert_eq!(eternalog.execute_upgrade(), Err(Error::NoPendingUpgrade));
+
+            let code_hash = Hash::from([1u8; 32]);
+            assert_eq!(eternalog.propose_upgrade(code_hash), Ok(()));
+
+            // The timelock hasn't elapsed yet, so execution is rejected and the
+            // pending upgrade must still be there for a later retry.
+            assert_eq!(eternalog.execute_upgrade(), Err(Error::UpgradeTimelocked));
+            assert_eq!(
+                eternalog.get_pending_upgrade().map(|(hash, _)| hash),
+                Some(code_hash)
+            );
+        }
+
+        #[ink::test]
+        fn cancel_upgrade_clears_pending_state() {
+            let mut eternalog = Eternalog::new(10, 0, FeeSink::Burn, 100);
+
+            let code_hash = Hash::from([1u8; 32]);
+            assert_eq!(eternalog.propose_upgrade(code_hash), Ok(()));
+            assert!(eternalog.get_pending_upgrade().is_some());
+
+            assert_eq!(eternalog.cancel_upgrade(), Ok(()));
+            assert_eq!(eternalog.get_pending_upgrade(), None);
+        }
+
+        #[ink::test]
+        fn hash_chain_tracks_stored_logs() {
+            let mut eternalog = Eternalog::new(10, 0, FeeSink::Burn, 100);
+            assert_eq!(eternalog.get_chain_head(), [0u8; 32]);
+            assert_eq!(eternalog.get_log_hash(1), None);
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(10);
+            let first_id = eternalog.store_log("first".to_string(), 1).unwrap();
+            let head_after_first = eternalog.get_chain_head();
+            assert_ne!(head_after_first, [0u8; 32]);
+            assert_eq!(eternalog.get_log_hash(first_id), Some(head_after_first));
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(10);
+            eternalog.store_log("second".to_string(), 1).unwrap();
+            assert_ne!(eternalog.get_chain_head(), head_after_first);
+
+            assert!(eternalog.verify_chain(1, 2));
+            // to_id beyond what has been stored can't be verified
+            assert!(!eternalog.verify_chain(1, 5));
+        }
+
+        #[ink::test]
+        fn verify_chain_detects_tampering() {
+            let mut eternalog = Eternalog::new(10, 0, FeeSink::Burn, 100);
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(10);
+            eternalog.store_log("first".to_string(), 1).unwrap();
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(10);
+            eternalog.store_log("second".to_string(), 1).unwrap();
+
+            assert!(eternalog.verify_chain(1, 2));
+
+            // Directly rewrite a stored entry, bypassing store_log, as a
+            // compromised node might. The recorded hash chain no longer
+            // matches the (now tampered) log contents.
+            eternalog.log_data.insert(1, &"tampered".to_string());
+
+            assert!(!eternalog.verify_chain(1, 2));
+        }
+
+        #[ink::test]
+        fn pause_blocks_store_log_and_resume_reenables_it() {
+            let mut eternalog = Eternalog::new(10, 0, FeeSink::Burn, 100);
+            assert!(!eternalog.is_paused());
+
+            assert_eq!(eternalog.pause(), Ok(()));
+            assert!(eternalog.is_paused());
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(10);
+            assert_eq!(
+                eternalog.store_log("test".to_string(), 1),
+                Err(Error::ContractPaused)
+            );
+
+            assert_eq!(eternalog.resume(), Ok(()));
+            assert!(!eternalog.is_paused());
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(10);
+            assert!(eternalog.store_log("test".to_string(), 1).is_ok());
+        }
+
+        #[ink::test]
+        fn only_owner_can_pause_or_resume() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut eternalog = Eternalog::new(10, 0, FeeSink::Burn, 100);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(eternalog.pause(), Err(Error::Unauthorized));
+            assert_eq!(eternalog.resume(), Err(Error::Unauthorized));
+        }
+
+        #[ink::test]
+        fn paginated_queries_respect_bounds() {
+            let mut eternalog = Eternalog::new(10, 0, FeeSink::Burn, 100);
+            for entry in ["log0", "log1", "log2", "log3", "log4"] {
+                ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(10);
+                eternalog.store_log(entry.to_string(), 1).unwrap();
+            }
+
+            let (page, total) = eternalog.get_logs_by_type_paged(1, 0, 2);
+            assert_eq!(total, 5);
+            assert_eq!(page, [1, 2]);
+
+            // a partial page at the tail
+            let (page, total) = eternalog.get_logs_by_type_paged(1, 4, 2);
+            assert_eq!(total, 5);
+            assert_eq!(page, [5]);
+
+            // start past the end returns an empty page, not an error
+            let (page, total) = eternalog.get_logs_by_type_paged(1, 10, 2);
+            assert_eq!(total, 5);
+            assert!(page.is_empty());
+
+            // limit 0 returns an empty page
+            let (page, total) = eternalog.get_logs_by_type_paged(1, 0, 0);
+            assert_eq!(total, 5);
+            assert!(page.is_empty());
+        }
+
+        #[ink::test]
+        fn search_logs_by_content_respects_the_given_id_range() {
+            let mut eternalog = Eternalog::new(10, 0, FeeSink::Burn, 100);
+            for entry in ["match-a", "match-b", "match-c"] {
+                ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(10);
+                eternalog.store_log(entry.to_string(), 1).unwrap();
+            }
+
+            // Searching the full range finds all three.
+            assert_eq!(
+                eternalog.search_logs_by_content("match".to_string(), 1, 3),
+                [1, 2, 3]
+            );
+
+            // Narrowing the range excludes matches outside it, so a caller
+            // can scan the log history in bounded chunks instead of always
+            // rescanning everything from log 1.
+            assert_eq!(
+                eternalog.search_logs_by_content("match".to_string(), 1, 1),
+                [1]
+            );
+
+            let (page, total) =
+                eternalog.search_logs_by_content_paged("match".to_string(), 2, 3, 0, 10);
+            assert_eq!(total, 2);
+            assert_eq!(page, [2, 3]);
+        }
+
+        #[ink::test]
+        fn get_logs_by_type_and_author_paged_reads_only_that_pairs_entries() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut eternalog = Eternalog::new(10, 0, FeeSink::Burn, 100);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(10);
+            eternalog.store_log("alice-1".to_string(), 1).unwrap();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(10);
+            eternalog.store_log("bob-1".to_string(), 1).unwrap();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(10);
+            eternalog.store_log("alice-2".to_string(), 1).unwrap();
+
+            let (page, total) =
+                eternalog.get_logs_by_type_and_author_paged(1, accounts.alice, 0, 10);
+            assert_eq!(total, 2);
+            assert_eq!(page, [1, 3]);
+        }
     }
 
     /// End-to-end tests
@@ -376,8 +1281,8 @@ mod eternalog {
                 .expect("instantiate failed");
             let call_builder = contract.call_builder::<Eternalog>();
 
-            let storage_fee = call_builder.get_storage_fee();
-            let get_result = client.call(&ink_e2e::alice(), &storage_fee).dry_run().await?;
+            let base_fee = call_builder.get_base_fee();
+            let get_result = client.call(&ink_e2e::alice(), &base_fee).dry_run().await?;
             assert_eq!(get_result.return_value(), 10);
 
             Ok(())
@@ -385,7 +1290,7 @@ mod eternalog {
 
         #[ink_e2e::test]
         async fn store_and_retrieve_log(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
-            let mut constructor = EternalogRef::new(100);
+            let mut constructor = EternalogRef::new(100, 0, FeeSink::Burn, 100);
             let contract = client
                 .instantiate("eternalog", &ink_e2e::bob(), &mut constructor)
                 .submit()